@@ -0,0 +1,153 @@
+use crate::error::Error;
+
+/// WGS84 ellipsoid semi-major axis, in metres.
+const WGS84_A: f64 = 6_378_137.0;
+/// WGS84 ellipsoid first eccentricity.
+const WGS84_E: f64 = 0.081_819_190_8;
+/// UTM central scale factor.
+const UTM_SCALE: f64 = 0.999_6;
+
+/// Tracks the nearest ancestor's `srsName`/`srsDimension` while walking down
+/// from `gml:MultiSurface` to `gml:posList`, since GML allows either to be
+/// declared once on an outer element and inherited by every descendant that
+/// does not repeat it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct SrsContext {
+    pub srs_name: Option<String>,
+    pub srs_dimension: Option<u8>,
+}
+
+impl SrsContext {
+    pub(crate) fn inherit(&self, srs_name: &Option<String>, srs_dimension: &Option<u8>) -> Self {
+        Self {
+            srs_name: srs_name.clone().or_else(|| self.srs_name.clone()),
+            srs_dimension: srs_dimension.or(self.srs_dimension),
+        }
+    }
+
+    pub(crate) fn resolved_epsg(&self) -> Result<Option<u32>, Error> {
+        self.srs_name.as_deref().map(parse_epsg).transpose()
+    }
+
+    pub(crate) fn resolved(&self) -> Result<ResolvedCrs, Error> {
+        Ok(ResolvedCrs {
+            srs_name: self.srs_name.clone(),
+            epsg: self.resolved_epsg()?,
+        })
+    }
+}
+
+/// The CRS a `gml:MultiSurface` was resolved against while parsing: the
+/// literal `srsName` it inherited (if any) alongside the EPSG code extracted
+/// from it. Exposed separately from `MultiSurface` itself, which the
+/// `egml_core` domain model keeps CRS-agnostic.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResolvedCrs {
+    pub srs_name: Option<String>,
+    pub epsg: Option<u32>,
+}
+
+/// Extracts the numeric EPSG code out of a `srsName` such as `"EPSG:25832"`
+/// or the URN form `"urn:ogc:def:crs:EPSG::25832"`.
+pub(crate) fn parse_epsg(srs_name: &str) -> Result<u32, Error> {
+    srs_name
+        .rsplit(':')
+        .next()
+        .and_then(|code| code.parse::<u32>().ok())
+        .ok_or_else(|| Error::UnsupportedSrs(srs_name.to_string()))
+}
+
+/// Target coordinate frame for the opt-in reprojection performed while parsing.
+#[derive(Debug, Clone, Copy)]
+pub enum ReprojectionTarget {
+    /// Geographic WGS84, longitude/latitude in degrees.
+    Wgs84,
+    /// A local East-North-Up frame relative to `origin`, which is assumed to
+    /// be expressed in the same projected CRS as the source coordinates. Only
+    /// the origin's X/Y are subtracted; heights keep their source value.
+    LocalEnu { origin: (f64, f64, f64) },
+}
+
+/// Reprojects a single coordinate. The Z component always passes through
+/// unchanged, since the source posLists here are always 3D with height as
+/// the third component.
+pub(crate) fn reproject_point(
+    x: f64,
+    y: f64,
+    z: f64,
+    from_epsg: u32,
+    target: ReprojectionTarget,
+) -> Result<(f64, f64, f64), Error> {
+    match target {
+        ReprojectionTarget::LocalEnu {
+            origin: (ox, oy, _),
+        } => Ok((x - ox, y - oy, z)),
+        ReprojectionTarget::Wgs84 => {
+            let zone = utm_zone_from_epsg(from_epsg)?;
+            // Southern-hemisphere zones carry a 10,000 km false northing.
+            let northing = if (32701..=32760).contains(&from_epsg) {
+                y - 10_000_000.0
+            } else {
+                y
+            };
+            let (lon, lat) = utm_to_wgs84(x, northing, zone);
+            Ok((lon, lat, z))
+        }
+    }
+}
+
+/// Maps a projected EPSG code onto its UTM zone, covering the ETRS89/UTM
+/// (`258xx`, always northern hemisphere) and WGS84/UTM (`326xx`/`327xx`)
+/// families used by the CityGML exports this crate reads.
+fn utm_zone_from_epsg(epsg: u32) -> Result<u8, Error> {
+    match epsg {
+        25800..=25899 => Ok((epsg - 25800) as u8),
+        32601..=32660 => Ok((epsg - 32600) as u8),
+        32701..=32760 => Ok((epsg - 32700) as u8),
+        other => Err(Error::UnsupportedSrs(format!("EPSG:{other}"))),
+    }
+}
+
+/// Inverse transverse Mercator (Snyder, 1987) for a UTM zone, taking the
+/// northing relative to the equator (any false northing already removed) and
+/// returning `(longitude, latitude)` in degrees.
+fn utm_to_wgs84(easting: f64, northing: f64, zone: u8) -> (f64, f64) {
+    let e2 = WGS84_E * WGS84_E;
+    let ep2 = e2 / (1.0 - e2);
+    let e1 = (1.0 - (1.0 - e2).sqrt()) / (1.0 + (1.0 - e2).sqrt());
+
+    let x = easting - 500_000.0;
+    let y = northing;
+
+    let m = y / UTM_SCALE;
+    let mu = m / (WGS84_A * (1.0 - e2 / 4.0 - 3.0 * e2 * e2 / 64.0 - 5.0 * e2 * e2 * e2 / 256.0));
+
+    let phi1 = mu
+        + (3.0 * e1 / 2.0 - 27.0 * e1.powi(3) / 32.0) * (2.0 * mu).sin()
+        + (21.0 * e1.powi(2) / 16.0 - 55.0 * e1.powi(4) / 32.0) * (4.0 * mu).sin()
+        + (151.0 * e1.powi(3) / 96.0) * (6.0 * mu).sin()
+        + (1097.0 * e1.powi(4) / 512.0) * (8.0 * mu).sin();
+
+    let sin_phi1 = phi1.sin();
+    let n1 = WGS84_A / (1.0 - e2 * sin_phi1 * sin_phi1).sqrt();
+    let t1 = phi1.tan().powi(2);
+    let c1 = ep2 * phi1.cos().powi(2);
+    let r1 = WGS84_A * (1.0 - e2) / (1.0 - e2 * sin_phi1 * sin_phi1).powf(1.5);
+    let d = x / (n1 * UTM_SCALE);
+
+    let lat = phi1
+        - (n1 * phi1.tan() / r1)
+            * (d * d / 2.0
+                - (5.0 + 3.0 * t1 + 10.0 * c1 - 4.0 * c1 * c1 - 9.0 * ep2) * d.powi(4) / 24.0
+                + (61.0 + 90.0 * t1 + 298.0 * c1 + 45.0 * t1 * t1 - 252.0 * ep2 - 3.0 * c1 * c1)
+                    * d.powi(6)
+                    / 720.0);
+
+    let lon = (d - (1.0 + 2.0 * t1 + c1) * d.powi(3) / 6.0
+        + (5.0 - 2.0 * c1 + 28.0 * t1 - 3.0 * c1 * c1 + 8.0 * ep2 + 24.0 * t1 * t1) * d.powi(5)
+            / 120.0)
+        / phi1.cos();
+
+    let lon_origin = (zone as f64 - 1.0) * 6.0 - 180.0 + 3.0;
+    (lon.to_degrees() + lon_origin, lat.to_degrees())
+}
@@ -0,0 +1,50 @@
+use thiserror::Error as ThisError;
+
+#[derive(Debug, ThisError)]
+pub enum Error {
+    #[error("failed to deserialize GML document: {0}")]
+    Deserialization(#[from] quick_xml::DeError),
+
+    #[error("failed to serialize GML document: {0}")]
+    Serialization(#[from] quick_xml::SeError),
+
+    #[error("failed to build geometry model: {0}")]
+    Model(#[from] egml_core::error::Error),
+
+    #[error("failed to read XML events: {0}")]
+    Xml(#[from] quick_xml::Error),
+
+    #[error("dangling xlink:href reference '{0}': no element with matching gml:id was found in the document")]
+    DanglingReference(String),
+
+    #[error("unsupported or unrecognized srsName '{0}'")]
+    UnsupportedSrs(String),
+
+    #[error("surface mixes srsDimension 2 and 3 across its posLists, which is not supported")]
+    MixedSrsDimension,
+
+    #[error("no gml:MultiSurface element found in the document")]
+    NotFound,
+
+    #[error("invalid coordinate value '{0}' in a gml:posList")]
+    InvalidCoordinate(String),
+
+    #[error("posList length is not a multiple of its srsDimension")]
+    MalformedPosList,
+
+    #[error("document is not valid UTF-8")]
+    InvalidUtf8,
+
+    #[error("reached end of document while scanning a {0} element")]
+    UnexpectedEof(String),
+
+    #[error("could not find a boundary vertex visible from an interior ring while bridging a hole for triangulation")]
+    NoVisibleBridgeVertex,
+
+    #[error("failed to parse element starting at byte offset {offset}: {source}")]
+    StreamElement {
+        offset: u64,
+        #[source]
+        source: Box<Error>,
+    },
+}
@@ -0,0 +1,85 @@
+use crate::error::Error;
+use quick_xml::de;
+use std::cell::RefCell;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use crate::geometry::context::ParseContext;
+use crate::geometry::registry::GeometryRegistry;
+use crate::geometry::surface_member::{build_polygons, GmlSurfaceMember};
+use egml_core::model::base::{Gml, Id};
+use egml_core::model::geometry::CompositeSurface;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, PartialEq, Eq, Hash, Clone)]
+#[serde(rename = "gml:CompositeSurface")]
+pub(crate) struct GmlCompositeSurface {
+    #[serde(rename = "@id", default)]
+    id: String,
+    #[serde(rename = "@srsName", default)]
+    srs_name: Option<String>,
+    #[serde(rename = "@srsDimension", default)]
+    srs_dimension: Option<u8>,
+    #[serde(rename = "$value")]
+    members: Vec<GmlSurfaceMember>,
+}
+
+impl TryFrom<GmlCompositeSurface> for CompositeSurface {
+    type Error = Error;
+
+    fn try_from(value: GmlCompositeSurface) -> Result<Self, Self::Error> {
+        let observed_dimension = RefCell::new(None);
+        build_composite_surface(value, &ParseContext::new(None, &observed_dimension))
+    }
+}
+
+pub(crate) fn build_composite_surface(
+    value: GmlCompositeSurface,
+    ctx: &ParseContext,
+) -> Result<CompositeSurface, Error> {
+    let id: Id = value.id.clone().try_into().ok().unwrap_or_else(|| {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        Id::from_hashed_u64(hasher.finish())
+    });
+    let gml = Gml::new(id);
+
+    let child_ctx = ctx.child(&value.srs_name, &value.srs_dimension);
+    let polygons = build_polygons(value.members, &child_ctx)?;
+
+    let composite_surface = CompositeSurface::new(gml, polygons)?;
+    Ok(composite_surface)
+}
+
+pub fn parse_composite_surface(source_text: &str) -> Result<CompositeSurface, Error> {
+    let registry = GeometryRegistry::build(source_text)?;
+    let parsed_geometry: GmlCompositeSurface = de::from_str(source_text)?;
+    let observed_dimension = RefCell::new(None);
+    build_composite_surface(
+        parsed_geometry,
+        &ParseContext::new(Some(&registry), &observed_dimension),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::composite_surface::parse_composite_surface;
+
+    #[test]
+    fn parsing_composite_surface() {
+        let source_text = "<gml:CompositeSurface gml:id=\"UUID_composite\">
+              <gml:surfaceMember>
+                <gml:Polygon gml:id=\"UUID_wall\">
+                  <gml:exterior>
+                    <gml:LinearRing>
+                      <gml:posList srsDimension=\"3\">678009.7116291433 5403638.313338383 417.3480034550211 678012.5609078613 5403634.960884141 417.34658523466385 678013.7892528991 5403636.004867206 417.51938733855997 678009.7116291433 5403638.313338383 417.3480034550211</gml:posList>
+                    </gml:LinearRing>
+                  </gml:exterior>
+                </gml:Polygon>
+              </gml:surfaceMember>
+            </gml:CompositeSurface>";
+
+        let result = parse_composite_surface(source_text).unwrap();
+
+        assert_eq!(result.surface_member().len(), 1);
+    }
+}
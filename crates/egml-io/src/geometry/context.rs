@@ -0,0 +1,58 @@
+use std::cell::RefCell;
+
+use crate::crs::{ReprojectionTarget, SrsContext};
+use crate::error::Error;
+use crate::geometry::registry::GeometryRegistry;
+
+/// Threaded through the `GmlMultiSurface` -> `GmlPolygon` -> `GmlLinearRing`
+/// -> `GmlPosList` conversion chain so that href resolution, inherited
+/// srsName/srsDimension and opt-in reprojection are all available at the
+/// leaf where coordinates are actually produced.
+pub(crate) struct ParseContext<'a> {
+    pub(crate) registry: Option<&'a GeometryRegistry<'a>>,
+    pub(crate) srs: SrsContext,
+    pub(crate) reprojection: Option<ReprojectionTarget>,
+    observed_dimension: &'a RefCell<Option<u8>>,
+}
+
+impl<'a> ParseContext<'a> {
+    pub(crate) fn new(
+        registry: Option<&'a GeometryRegistry<'a>>,
+        observed_dimension: &'a RefCell<Option<u8>>,
+    ) -> Self {
+        Self {
+            registry,
+            srs: SrsContext::default(),
+            reprojection: None,
+            observed_dimension,
+        }
+    }
+
+    pub(crate) fn child(
+        &self,
+        srs_name: &Option<String>,
+        srs_dimension: &Option<u8>,
+    ) -> ParseContext<'a> {
+        ParseContext {
+            registry: self.registry,
+            srs: self.srs.inherit(srs_name, srs_dimension),
+            reprojection: self.reprojection,
+            observed_dimension: self.observed_dimension,
+        }
+    }
+
+    /// Records the srsDimension that applies to a posList being parsed under
+    /// this context, erroring if a previous posList in the same surface
+    /// resolved to a different dimension.
+    pub(crate) fn check_dimension(&self, dimension: u8) -> Result<(), Error> {
+        let mut observed = self.observed_dimension.borrow_mut();
+        match *observed {
+            Some(previous) if previous != dimension => Err(Error::MixedSrsDimension),
+            Some(_) => Ok(()),
+            None => {
+                *observed = Some(dimension);
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,10 @@
+pub mod composite_surface;
+pub(crate) mod context;
+pub mod multi_surface;
+pub mod polygon;
+pub mod registry;
+pub mod solid;
+pub mod stream;
+pub(crate) mod surface_member;
+pub mod write;
+pub(crate) mod xml_span;
@@ -1,61 +1,148 @@
 use crate::error::Error;
-use quick_xml::de;
+use std::cell::RefCell;
 use std::hash::{DefaultHasher, Hash, Hasher};
 
-use crate::geometry::polygon::GmlPolygon;
+use crate::crs::{ReprojectionTarget, ResolvedCrs, SrsContext};
+use crate::geometry::context::ParseContext;
+use crate::geometry::stream::MultiSurfaceStream;
+use crate::geometry::surface_member::{
+    build_polygons, to_gml_surface_member, GmlSurfaceMember, GmlSurfaceMemberWrite,
+};
 use egml_core::model::base::{Gml, Id};
-use egml_core::model::geometry::{MultiSurface, Polygon};
+use egml_core::model::geometry::MultiSurface;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
+#[derive(Debug, Deserialize, PartialEq, Eq, Hash, Clone)]
 #[serde(rename = "gml:MultiSurface")]
-struct GmlMultiSurface {
+pub(crate) struct GmlMultiSurface {
     #[serde(rename = "@id", default)]
     id: String,
+    #[serde(rename = "@srsName", default)]
+    srs_name: Option<String>,
+    #[serde(rename = "@srsDimension", default)]
+    srs_dimension: Option<u8>,
     #[serde(rename = "$value")]
     members: Vec<GmlSurfaceMember>,
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
-#[serde(rename = "gml:surfaceMember")]
-pub struct GmlSurfaceMember {
-    #[serde(rename = "@href", default)]
-    href: String,
+/// Write-side mirror of [`GmlMultiSurface`], skipping `srsName`/`srsDimension`
+/// when absent instead of emitting them as empty attributes, and keeping
+/// `gml:id` namespaced. As the document root, this is also where the
+/// `gml`/`xlink` namespace declarations the rest of the tree's prefixes rely
+/// on are emitted, so the output is a self-contained, conformant GML document
+/// rather than a fragment with undeclared namespace prefixes.
+#[derive(Debug, Serialize)]
+#[serde(rename = "gml:MultiSurface")]
+pub(crate) struct GmlMultiSurfaceWrite {
+    #[serde(rename = "@xmlns:gml")]
+    xmlns_gml: &'static str,
+    #[serde(rename = "@xmlns:xlink")]
+    xmlns_xlink: &'static str,
+    #[serde(rename = "@gml:id", skip_serializing_if = "String::is_empty")]
+    id: String,
+    #[serde(rename = "@srsName", skip_serializing_if = "Option::is_none")]
+    srs_name: Option<String>,
+    #[serde(rename = "@srsDimension", skip_serializing_if = "Option::is_none")]
+    srs_dimension: Option<u8>,
     #[serde(rename = "$value")]
-    pub polygon: Option<GmlPolygon>,
+    members: Vec<GmlSurfaceMemberWrite>,
 }
 
+const GML_NAMESPACE: &str = "http://www.opengis.net/gml";
+const XLINK_NAMESPACE: &str = "http://www.w3.org/1999/xlink";
+
 impl TryFrom<GmlMultiSurface> for MultiSurface {
     type Error = Error;
 
     fn try_from(value: GmlMultiSurface) -> Result<Self, Self::Error> {
-        let id: Id = value.id.clone().try_into().ok().unwrap_or_else(|| {
-            let mut hasher = DefaultHasher::new();
-            value.hash(&mut hasher);
-            Id::from_hashed_u64(hasher.finish())
-        });
-        let gml = Gml::new(id);
-
-        let polygons: Vec<Polygon> = value
-            .members
-            .into_iter()
-            .flat_map(|x| x.polygon)
-            .map(|x| x.try_into())
-            .collect::<Result<Vec<_>, _>>()?;
-
-        let multi_surface = MultiSurface::new(gml, polygons)?;
-        Ok(multi_surface)
+        let observed_dimension = RefCell::new(None);
+        build_multi_surface(value, &ParseContext::new(None, &observed_dimension)).map(|(ms, _)| ms)
+    }
+}
+
+/// Builds the [`MultiSurface`] plus the [`SrsContext`] that was resolved for
+/// it (its own `srsName`/`srsDimension`, inherited from `ctx` if not repeated
+/// locally), so callers that need the CRS a document was parsed in - rather
+/// than just the geometry - can get at it without re-walking the XML.
+pub(crate) fn build_multi_surface(
+    value: GmlMultiSurface,
+    ctx: &ParseContext,
+) -> Result<(MultiSurface, SrsContext), Error> {
+    let id: Id = value.id.clone().try_into().ok().unwrap_or_else(|| {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        Id::from_hashed_u64(hasher.finish())
+    });
+    let gml = Gml::new(id);
+
+    let child_ctx = ctx.child(&value.srs_name, &value.srs_dimension);
+    let polygons = build_polygons(value.members, &child_ctx)?;
+
+    let multi_surface = MultiSurface::new(gml, polygons)?;
+    Ok((multi_surface, child_ctx.srs))
+}
+
+/// Inverse of [`build_multi_surface`]: re-emits a multi-surface's `gml:id`
+/// and members as a [`GmlMultiSurfaceWrite`] ready to be serialized back to
+/// XML, tagging it with `srs_name` (if given) and the srsDimension points are
+/// always written in.
+pub(crate) fn to_gml_multi_surface(
+    value: &MultiSurface,
+    srs_name: Option<String>,
+    precision: usize,
+) -> GmlMultiSurfaceWrite {
+    GmlMultiSurfaceWrite {
+        xmlns_gml: GML_NAMESPACE,
+        xmlns_xlink: XLINK_NAMESPACE,
+        id: value.id().to_string(),
+        srs_name,
+        srs_dimension: Some(3),
+        members: value
+            .surface_member()
+            .iter()
+            .map(|polygon| to_gml_surface_member(polygon, precision))
+            .collect(),
     }
 }
 
+/// Parses the first (and, for this API, only) `gml:MultiSurface` in
+/// `source_text`. This is a thin wrapper over [`MultiSurfaceStream`]: for
+/// documents containing more than one `gml:MultiSurface`, stream it directly
+/// instead.
 pub fn parse_multi_surface(source_text: &str) -> Result<MultiSurface, Error> {
-    let parsed_geometry: GmlMultiSurface = de::from_str(source_text)?;
-    parsed_geometry.try_into()
+    MultiSurfaceStream::new(source_text)?
+        .next()
+        .ok_or(Error::NotFound)?
+}
+
+/// Like [`parse_multi_surface`], but reprojects every coordinate from the
+/// document's resolved `srsName` into `target` as it is parsed.
+pub fn parse_multi_surface_reprojected(
+    source_text: &str,
+    target: ReprojectionTarget,
+) -> Result<MultiSurface, Error> {
+    MultiSurfaceStream::new(source_text)?
+        .with_reprojection(target)
+        .next()
+        .ok_or(Error::NotFound)?
+}
+
+/// Like [`parse_multi_surface`], but also returns the [`ResolvedCrs`] the
+/// `gml:MultiSurface` was parsed in, since the `MultiSurface` model itself is
+/// CRS-agnostic and otherwise has nowhere to keep it.
+pub fn parse_multi_surface_with_crs(
+    source_text: &str,
+) -> Result<(MultiSurface, ResolvedCrs), Error> {
+    MultiSurfaceStream::new(source_text)?
+        .next_with_crs()
+        .ok_or(Error::NotFound)?
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::crs::ReprojectionTarget;
     use crate::parse_multi_surface;
+    use crate::parse_multi_surface_reprojected;
 
     #[test]
     fn parsing_multi_surface() {
@@ -209,4 +296,100 @@ mod tests {
 
         assert_eq!(result.surface_member().len(), 1);
     }
+
+    #[test]
+    fn parsing_multi_surface_with_xlink_href() {
+        let source_text = "<gml:MultiSurface>
+              <gml:surfaceMember>
+                <gml:Polygon gml:id=\"UUID_shared\">
+                  <gml:exterior>
+                    <gml:LinearRing>
+                      <gml:posList srsDimension=\"3\">678009.7116291433 5403638.313338383 417.3480034550211 678012.5609078613 5403634.960884141 417.34658523466385 678013.7892528991 5403636.004867206 417.51938733855997 678010.9399743223 5403639.357321232 417.5208051908512 678009.7116291433 5403638.313338383 417.3480034550211</gml:posList>
+                    </gml:LinearRing>
+                  </gml:exterior>
+                </gml:Polygon>
+              </gml:surfaceMember>
+              <gml:surfaceMember xlink:href=\"#UUID_shared\"/>
+            </gml:MultiSurface>";
+
+        let result = parse_multi_surface(source_text).unwrap();
+
+        assert_eq!(result.surface_member().len(), 2);
+    }
+
+    #[test]
+    fn parsing_multi_surface_with_dangling_href_fails() {
+        let source_text = "<gml:MultiSurface>
+              <gml:surfaceMember xlink:href=\"#does_not_exist\"/>
+            </gml:MultiSurface>";
+
+        let result = parse_multi_surface(source_text);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parsing_multi_surface_rejects_mixed_srs_dimension() {
+        let source_text = "<gml:MultiSurface srsName=\"EPSG:25832\">
+              <gml:surfaceMember>
+                <gml:Polygon>
+                  <gml:exterior>
+                    <gml:LinearRing>
+                      <gml:posList srsDimension=\"2\">0 0 1 0 1 1 0 1 0 0</gml:posList>
+                    </gml:LinearRing>
+                  </gml:exterior>
+                </gml:Polygon>
+              </gml:surfaceMember>
+              <gml:surfaceMember>
+                <gml:Polygon>
+                  <gml:exterior>
+                    <gml:LinearRing>
+                      <gml:posList srsDimension=\"3\">0 0 0 1 0 0 1 1 0 0 1 0 0 0 0</gml:posList>
+                    </gml:LinearRing>
+                  </gml:exterior>
+                </gml:Polygon>
+              </gml:surfaceMember>
+            </gml:MultiSurface>";
+
+        let result = parse_multi_surface(source_text);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parsing_multi_surface_with_non_numeric_srs_name_without_reprojection() {
+        let source_text = "<gml:MultiSurface srsName=\"urn:adv:crs:ETRS89_UTM32*DE_DHHN2016_NH\" srsDimension=\"3\">
+              <gml:surfaceMember>
+                <gml:Polygon>
+                  <gml:exterior>
+                    <gml:LinearRing>
+                      <gml:posList>0 0 0 1 0 0 1 1 0 0 1 0 0 0 0</gml:posList>
+                    </gml:LinearRing>
+                  </gml:exterior>
+                </gml:Polygon>
+              </gml:surfaceMember>
+            </gml:MultiSurface>";
+
+        let result = parse_multi_surface(source_text).unwrap();
+
+        assert_eq!(result.surface_member().len(), 1);
+    }
+
+    #[test]
+    fn reprojecting_multi_surface_to_wgs84() {
+        let source_text = "<gml:MultiSurface srsName=\"EPSG:25832\" srsDimension=\"3\">
+              <gml:surfaceMember>
+                <gml:Polygon>
+                  <gml:exterior>
+                    <gml:LinearRing>
+                      <gml:posList>678009.7116291433 5403638.313338383 417.3480034550211 678012.5609078613 5403634.960884141 417.34658523466385 678013.7892528991 5403636.004867206 417.51938733855997 678009.7116291433 5403638.313338383 417.3480034550211</gml:posList>
+                    </gml:LinearRing>
+                  </gml:exterior>
+                </gml:Polygon>
+              </gml:surfaceMember>
+            </gml:MultiSurface>";
+
+        let _result =
+            parse_multi_surface_reprojected(source_text, ReprojectionTarget::Wgs84).unwrap();
+    }
 }
@@ -0,0 +1,250 @@
+use crate::crs::reproject_point;
+use crate::error::Error;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use egml_core::model::base::{Gml, Id};
+use egml_core::model::geometry::{Point3D, Polygon, Ring};
+use serde::{Deserialize, Serialize};
+
+use crate::geometry::context::ParseContext;
+
+#[derive(Debug, Deserialize, PartialEq, Eq, Hash, Clone)]
+#[serde(rename = "gml:Polygon")]
+pub struct GmlPolygon {
+    #[serde(rename = "@id", default)]
+    id: String,
+    #[serde(rename = "@srsName", default)]
+    srs_name: Option<String>,
+    #[serde(rename = "@srsDimension", default)]
+    srs_dimension: Option<u8>,
+    #[serde(rename = "gml:exterior")]
+    exterior: GmlRingMember,
+    #[serde(rename = "gml:interior", default)]
+    interior: Vec<GmlRingMember>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq, Hash, Clone)]
+pub struct GmlRingMember {
+    #[serde(rename = "gml:LinearRing")]
+    linear_ring: GmlLinearRing,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq, Hash, Clone)]
+pub struct GmlLinearRing {
+    #[serde(rename = "@id", default)]
+    id: String,
+    #[serde(rename = "@srsName", default)]
+    srs_name: Option<String>,
+    #[serde(rename = "@srsDimension", default)]
+    srs_dimension: Option<u8>,
+    #[serde(rename = "gml:posList")]
+    pos_list: GmlPosList,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq, Hash, Clone)]
+pub struct GmlPosList {
+    #[serde(rename = "@srsDimension", default)]
+    srs_dimension: Option<u8>,
+    #[serde(rename = "$text")]
+    text: String,
+}
+
+/// Write-side mirror of [`GmlPolygon`]/[`GmlRingMember`]/[`GmlLinearRing`].
+/// Unlike the read structs, absent optional attributes are skipped entirely
+/// rather than emitted as empty strings (quick-xml happily writes
+/// `Option::<u8>::None` as `srsDimension=""`, which then fails to re-parse as
+/// a `u8`), and `gml:id` keeps its namespace prefix so the attribute survives
+/// under that prefix rather than being silently dropped by a conformant GML
+/// consumer. The `gml`/`xlink` namespace declarations the prefix relies on
+/// are emitted once, on the enclosing `gml:MultiSurface` root element.
+#[derive(Debug, Serialize)]
+#[serde(rename = "gml:Polygon")]
+pub(crate) struct GmlPolygonWrite {
+    #[serde(rename = "@gml:id", skip_serializing_if = "String::is_empty")]
+    id: String,
+    #[serde(rename = "@srsName", skip_serializing_if = "Option::is_none")]
+    srs_name: Option<String>,
+    #[serde(rename = "@srsDimension", skip_serializing_if = "Option::is_none")]
+    srs_dimension: Option<u8>,
+    #[serde(rename = "gml:exterior")]
+    exterior: GmlRingMemberWrite,
+    #[serde(rename = "gml:interior", skip_serializing_if = "Vec::is_empty")]
+    interior: Vec<GmlRingMemberWrite>,
+}
+
+#[derive(Debug, Serialize)]
+struct GmlRingMemberWrite {
+    #[serde(rename = "gml:LinearRing")]
+    linear_ring: GmlLinearRingWrite,
+}
+
+#[derive(Debug, Serialize)]
+struct GmlLinearRingWrite {
+    #[serde(rename = "@gml:id", skip_serializing_if = "String::is_empty")]
+    id: String,
+    #[serde(rename = "@srsName", skip_serializing_if = "Option::is_none")]
+    srs_name: Option<String>,
+    #[serde(rename = "@srsDimension", skip_serializing_if = "Option::is_none")]
+    srs_dimension: Option<u8>,
+    #[serde(rename = "gml:posList")]
+    pos_list: GmlPosListWrite,
+}
+
+#[derive(Debug, Serialize)]
+struct GmlPosListWrite {
+    #[serde(rename = "@srsDimension", skip_serializing_if = "Option::is_none")]
+    srs_dimension: Option<u8>,
+    #[serde(rename = "$text")]
+    text: String,
+}
+
+impl GmlPosList {
+    fn to_points(&self, ctx: &ParseContext) -> Result<Vec<Point3D>, Error> {
+        let ctx = ctx.child(&None, &self.srs_dimension);
+        let dimension = ctx.srs.srs_dimension.unwrap_or(3);
+        ctx.check_dimension(dimension)?;
+
+        let values: Vec<f64> = self
+            .text
+            .split_whitespace()
+            .map(|v| {
+                v.parse::<f64>()
+                    .map_err(|_| Error::InvalidCoordinate(v.to_string()))
+            })
+            .collect::<Result<_, _>>()?;
+        if values.len() % dimension as usize != 0 {
+            return Err(Error::MalformedPosList);
+        }
+
+        // Only resolve `srsName` into a numeric EPSG code when it's actually
+        // needed to drive reprojection: `parse_epsg` requires the trailing
+        // segment of `srsName` to be numeric, which rejects non-numeric CRS
+        // identifiers such as the `urn:adv:crs:ETRS89_UTM32*DE_DHHN2016_NH`
+        // form German CityGML exports use, and those must still parse fine
+        // when no reprojection was requested.
+        let reprojection = match ctx.reprojection {
+            Some(target) => match ctx.srs.resolved_epsg()? {
+                Some(epsg) => Some((epsg, target)),
+                None => {
+                    return Err(Error::UnsupportedSrs(
+                        "no srsName to reproject from".to_string(),
+                    ))
+                }
+            },
+            None => None,
+        };
+
+        values
+            .chunks(dimension as usize)
+            .map(|chunk| {
+                let (x, y, z) = match chunk {
+                    [x, y, z] => (*x, *y, *z),
+                    [x, y] => (*x, *y, 0.0),
+                    _ => return Err(Error::MalformedPosList),
+                };
+
+                let (x, y, z) = match reprojection {
+                    Some((epsg, target)) => reproject_point(x, y, z, epsg, target)?,
+                    None => (x, y, z),
+                };
+
+                Ok(Point3D::new(x, y, z))
+            })
+            .collect()
+    }
+}
+
+fn build_ring(value: GmlRingMember, ctx: &ParseContext) -> Result<Ring, Error> {
+    let ring = &value.linear_ring;
+    let ctx = ctx.child(&ring.srs_name, &ring.srs_dimension);
+    let points = ring.pos_list.to_points(&ctx)?;
+    let ring = Ring::new(points)?;
+    Ok(ring)
+}
+
+impl TryFrom<GmlRingMember> for Ring {
+    type Error = Error;
+
+    fn try_from(value: GmlRingMember) -> Result<Self, Self::Error> {
+        let observed_dimension = std::cell::RefCell::new(None);
+        build_ring(value, &ParseContext::new(None, &observed_dimension))
+    }
+}
+
+impl GmlPolygon {
+    pub(crate) fn try_into_with(self, ctx: &ParseContext) -> Result<Polygon, Error> {
+        build_polygon(self, ctx)
+    }
+}
+
+fn build_polygon(value: GmlPolygon, ctx: &ParseContext) -> Result<Polygon, Error> {
+    let id: Id = value.id.clone().try_into().ok().unwrap_or_else(|| {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        Id::from_hashed_u64(hasher.finish())
+    });
+    let gml = Gml::new(id);
+
+    let ctx = ctx.child(&value.srs_name, &value.srs_dimension);
+
+    let exterior = build_ring(value.exterior, &ctx)?;
+    let interior: Vec<Ring> = value
+        .interior
+        .into_iter()
+        .map(|x| build_ring(x, &ctx))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let polygon = Polygon::new(gml, exterior, interior)?;
+    Ok(polygon)
+}
+
+impl TryFrom<GmlPolygon> for Polygon {
+    type Error = Error;
+
+    fn try_from(value: GmlPolygon) -> Result<Self, Self::Error> {
+        let observed_dimension = std::cell::RefCell::new(None);
+        build_polygon(value, &ParseContext::new(None, &observed_dimension))
+    }
+}
+
+/// Inverse of [`build_polygon`]: re-emits a polygon's `gml:id` and rings as a
+/// [`GmlPolygonWrite`] ready to be serialized back to XML. The polygon's own
+/// `srsName`/`srsDimension` are left unset, relying on the enclosing
+/// `gml:MultiSurface` to carry them, matching how [`ParseContext`] inherits
+/// them back down on read.
+pub(crate) fn to_gml_polygon(value: &Polygon, precision: usize) -> GmlPolygonWrite {
+    GmlPolygonWrite {
+        id: value.id().to_string(),
+        srs_name: None,
+        srs_dimension: None,
+        exterior: to_gml_ring_member(value.exterior(), precision),
+        interior: value
+            .interior()
+            .iter()
+            .map(|ring| to_gml_ring_member(ring, precision))
+            .collect(),
+    }
+}
+
+fn to_gml_ring_member(ring: &Ring, precision: usize) -> GmlRingMemberWrite {
+    GmlRingMemberWrite {
+        linear_ring: GmlLinearRingWrite {
+            id: String::new(),
+            srs_name: None,
+            srs_dimension: None,
+            pos_list: GmlPosListWrite {
+                srs_dimension: None,
+                text: format_pos_list(ring.points(), precision),
+            },
+        },
+    }
+}
+
+fn format_pos_list(points: &[Point3D], precision: usize) -> String {
+    points
+        .iter()
+        .flat_map(|p| [p.x(), p.y(), p.z()])
+        .map(|value| format!("{value:.precision$}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use quick_xml::events::Event;
+use quick_xml::reader::Reader;
+
+use crate::error::Error;
+use crate::geometry::polygon::GmlPolygon;
+use crate::geometry::xml_span::scan_element_range;
+
+/// Maps `gml:id` to the byte range of the element carrying it within
+/// `source`, scanned once up front so that later `xlink:href="#id"`
+/// references can be dereferenced without re-walking the whole document for
+/// every occurrence. Indexing byte ranges rather than copying out each
+/// element's XML keeps the registry's own footprint proportional to the
+/// number of distinct ids rather than to the combined size of every
+/// `gml:Polygon` in the document; `source` itself still has to be held by
+/// the caller in full, since a href can point anywhere in it.
+#[derive(Debug, Default)]
+pub struct GeometryRegistry<'a> {
+    source: &'a str,
+    polygons_by_id: HashMap<String, Range<usize>>,
+}
+
+impl<'a> GeometryRegistry<'a> {
+    pub fn build(source_text: &'a str) -> Result<Self, Error> {
+        let mut reader = Reader::from_str(source_text);
+        reader.config_mut().trim_text(true);
+
+        let mut polygons_by_id = HashMap::new();
+        let mut buf = Vec::new();
+        loop {
+            let position = reader.buffer_position();
+            match reader.read_event_into(&mut buf)? {
+                Event::Eof => break,
+                Event::Start(start) if start.name().as_ref() == b"gml:Polygon" => {
+                    let id = start
+                        .attributes()
+                        .flatten()
+                        .find(|attr| attr.key.as_ref() == b"gml:id" || attr.key.as_ref() == b"id")
+                        .and_then(|attr| attr.unescape_value().ok().map(|v| v.into_owned()));
+
+                    let range = scan_element_range(&mut reader, b"gml:Polygon", position)?;
+
+                    if let Some(id) = id {
+                        polygons_by_id.insert(id, range);
+                    }
+                }
+                _ => {}
+            }
+            buf.clear();
+        }
+
+        Ok(Self {
+            source: source_text,
+            polygons_by_id,
+        })
+    }
+
+    /// Resolves an `xlink:href` such as `"#UUID_..."` into the [`GmlPolygon`]
+    /// it points at, slicing its XML out of `source` and parsing it on each
+    /// call; parsed polygons are not cached.
+    pub fn resolve_polygon(&self, href: &str) -> Result<GmlPolygon, Error> {
+        let id = href.strip_prefix('#').unwrap_or(href);
+        let range = self
+            .polygons_by_id
+            .get(id)
+            .ok_or_else(|| Error::DanglingReference(href.to_string()))?;
+
+        let polygon: GmlPolygon = quick_xml::de::from_str(&self.source[range.clone()])?;
+        Ok(polygon)
+    }
+}
@@ -0,0 +1,114 @@
+use crate::error::Error;
+use quick_xml::de;
+use std::cell::RefCell;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use crate::geometry::composite_surface::{build_composite_surface, GmlCompositeSurface};
+use crate::geometry::context::ParseContext;
+use crate::geometry::registry::GeometryRegistry;
+use egml_core::model::base::{Gml, Id};
+use egml_core::model::geometry::{CompositeSurface, Solid};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, PartialEq, Eq, Hash, Clone)]
+#[serde(rename = "gml:Solid")]
+struct GmlSolid {
+    #[serde(rename = "@id", default)]
+    id: String,
+    #[serde(rename = "@srsName", default)]
+    srs_name: Option<String>,
+    #[serde(rename = "@srsDimension", default)]
+    srs_dimension: Option<u8>,
+    #[serde(rename = "gml:exterior")]
+    exterior: GmlShellMember,
+    #[serde(rename = "gml:interior", default)]
+    interior: Vec<GmlShellMember>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq, Hash, Clone)]
+struct GmlShellMember {
+    #[serde(rename = "gml:CompositeSurface")]
+    composite_surface: GmlCompositeSurface,
+}
+
+impl TryFrom<GmlSolid> for Solid {
+    type Error = Error;
+
+    fn try_from(value: GmlSolid) -> Result<Self, Self::Error> {
+        let observed_dimension = RefCell::new(None);
+        build_solid(value, &ParseContext::new(None, &observed_dimension))
+    }
+}
+
+fn build_solid(value: GmlSolid, ctx: &ParseContext) -> Result<Solid, Error> {
+    let id: Id = value.id.clone().try_into().ok().unwrap_or_else(|| {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        Id::from_hashed_u64(hasher.finish())
+    });
+    let gml = Gml::new(id);
+
+    let child_ctx = ctx.child(&value.srs_name, &value.srs_dimension);
+
+    let exterior: CompositeSurface =
+        build_composite_surface(value.exterior.composite_surface, &child_ctx)?;
+    let interior: Vec<CompositeSurface> = value
+        .interior
+        .into_iter()
+        .map(|shell| build_composite_surface(shell.composite_surface, &child_ctx))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let solid = Solid::new(gml, exterior, interior)?;
+    Ok(solid)
+}
+
+pub fn parse_solid(source_text: &str) -> Result<Solid, Error> {
+    let registry = GeometryRegistry::build(source_text)?;
+    let parsed_geometry: GmlSolid = de::from_str(source_text)?;
+    let observed_dimension = RefCell::new(None);
+    build_solid(
+        parsed_geometry,
+        &ParseContext::new(Some(&registry), &observed_dimension),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::solid::parse_solid;
+
+    #[test]
+    fn parsing_solid_with_exterior_and_interior_shell() {
+        let source_text = "<gml:Solid gml:id=\"UUID_building\" srsName=\"EPSG:25832\" srsDimension=\"3\">
+              <gml:exterior>
+                <gml:CompositeSurface>
+                  <gml:surfaceMember>
+                    <gml:Polygon gml:id=\"UUID_wall\">
+                      <gml:exterior>
+                        <gml:LinearRing>
+                          <gml:posList>678009.71 5403638.31 417.35 678012.56 5403634.96 417.35 678013.79 5403636.00 417.52 678009.71 5403638.31 417.35</gml:posList>
+                        </gml:LinearRing>
+                      </gml:exterior>
+                    </gml:Polygon>
+                  </gml:surfaceMember>
+                </gml:CompositeSurface>
+              </gml:exterior>
+              <gml:interior>
+                <gml:CompositeSurface>
+                  <gml:surfaceMember>
+                    <gml:Polygon gml:id=\"UUID_void\">
+                      <gml:exterior>
+                        <gml:LinearRing>
+                          <gml:posList>678010.71 5403639.31 417.35 678011.56 5403635.96 417.35 678012.79 5403637.00 417.52 678010.71 5403639.31 417.35</gml:posList>
+                        </gml:LinearRing>
+                      </gml:exterior>
+                    </gml:Polygon>
+                  </gml:surfaceMember>
+                </gml:CompositeSurface>
+              </gml:interior>
+            </gml:Solid>";
+
+        let result = parse_solid(source_text).unwrap();
+
+        assert_eq!(result.interior().len(), 1);
+    }
+}
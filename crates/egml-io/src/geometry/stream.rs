@@ -0,0 +1,197 @@
+use std::cell::RefCell;
+
+use quick_xml::events::Event;
+use quick_xml::reader::Reader;
+
+use egml_core::model::geometry::MultiSurface;
+
+use crate::crs::{ReprojectionTarget, ResolvedCrs, SrsContext};
+use crate::error::Error;
+use crate::geometry::context::ParseContext;
+use crate::geometry::multi_surface::{build_multi_surface, GmlMultiSurface};
+use crate::geometry::registry::GeometryRegistry;
+use crate::geometry::xml_span::scan_element_range;
+
+/// Pull-parses a CityGML document one `gml:MultiSurface` at a time instead
+/// of deserializing the whole document into a single struct tree up front,
+/// so a caller can walk a multi-gigabyte city model building-by-building.
+///
+/// The href registry is still built with one upfront scan (hrefs can point
+/// anywhere in the document) and needs the whole `source_text` kept in
+/// memory for that, but it only indexes `gml:id` -> byte range rather than
+/// copying every `gml:Polygon`'s XML out of the document, so its own
+/// footprint stays proportional to the number of distinct ids rather than to
+/// the size of the document. Every yielded [`MultiSurface`] is otherwise
+/// parsed from just its own slice of `source_text`.
+pub struct MultiSurfaceStream<'a> {
+    source: &'a str,
+    reader: Reader<&'a [u8]>,
+    registry: GeometryRegistry<'a>,
+    reprojection: Option<ReprojectionTarget>,
+}
+
+impl<'a> MultiSurfaceStream<'a> {
+    pub fn new(source_text: &'a str) -> Result<Self, Error> {
+        let registry = GeometryRegistry::build(source_text)?;
+        let mut reader = Reader::from_str(source_text);
+        reader.config_mut().trim_text(true);
+        Ok(Self {
+            source: source_text,
+            reader,
+            registry,
+            reprojection: None,
+        })
+    }
+
+    pub fn with_reprojection(mut self, target: ReprojectionTarget) -> Self {
+        self.reprojection = Some(target);
+        self
+    }
+
+    /// Like [`Iterator::next`], but also returns the [`ResolvedCrs`] the
+    /// yielded `MultiSurface` was parsed in.
+    pub fn next_with_crs(&mut self) -> Option<Result<(MultiSurface, ResolvedCrs), Error>> {
+        self.advance().map(|result| {
+            result.and_then(|(multi_surface, srs)| Ok((multi_surface, srs.resolved()?)))
+        })
+    }
+
+    fn advance(&mut self) -> Option<Result<(MultiSurface, SrsContext), Error>> {
+        let mut buf = Vec::new();
+        loop {
+            let start_position = self.reader.buffer_position();
+            let event = match self.reader.read_event_into(&mut buf) {
+                Ok(event) => event,
+                Err(source) => {
+                    return Some(Err(Error::StreamElement {
+                        offset: start_position,
+                        source: Box::new(source.into()),
+                    }))
+                }
+            };
+
+            match event {
+                Event::Eof => return None,
+                Event::Start(start) if start.name().as_ref() == b"gml:MultiSurface" => {
+                    return Some(self.parse_at(start_position));
+                }
+                _ => {
+                    buf.clear();
+                    continue;
+                }
+            }
+        }
+    }
+
+    fn parse_at(&mut self, start_position: u64) -> Result<(MultiSurface, SrsContext), Error> {
+        let range = scan_element_range(&mut self.reader, b"gml:MultiSurface", start_position)?;
+        let fragment = &self.source[range];
+
+        let parsed: GmlMultiSurface =
+            quick_xml::de::from_str(fragment).map_err(|source| Error::StreamElement {
+                offset: start_position,
+                source: Box::new(source.into()),
+            })?;
+
+        let observed_dimension = RefCell::new(None);
+        let mut ctx = ParseContext::new(Some(&self.registry), &observed_dimension);
+        ctx.reprojection = self.reprojection;
+        build_multi_surface(parsed, &ctx).map_err(|source| Error::StreamElement {
+            offset: start_position,
+            source: Box::new(source),
+        })
+    }
+}
+
+impl<'a> Iterator for MultiSurfaceStream<'a> {
+    type Item = Result<MultiSurface, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.advance()
+            .map(|result| result.map(|(multi_surface, _)| multi_surface))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::geometry::stream::MultiSurfaceStream;
+
+    #[test]
+    fn streaming_yields_each_multi_surface_in_order() {
+        let source_text = "<city:CityModel>
+              <gml:MultiSurface gml:id=\"a\">
+                <gml:surfaceMember>
+                  <gml:Polygon>
+                    <gml:exterior>
+                      <gml:LinearRing>
+                        <gml:posList srsDimension=\"3\">0 0 0 1 0 0 1 1 0 0 1 0 0 0 0</gml:posList>
+                      </gml:LinearRing>
+                    </gml:exterior>
+                  </gml:Polygon>
+                </gml:surfaceMember>
+              </gml:MultiSurface>
+              <gml:MultiSurface gml:id=\"b\">
+                <gml:surfaceMember>
+                  <gml:Polygon>
+                    <gml:exterior>
+                      <gml:LinearRing>
+                        <gml:posList srsDimension=\"3\">2 0 0 3 0 0 3 1 0 2 1 0 2 0 0</gml:posList>
+                      </gml:LinearRing>
+                    </gml:exterior>
+                  </gml:Polygon>
+                </gml:surfaceMember>
+              </gml:MultiSurface>
+            </city:CityModel>";
+
+        let results: Vec<_> = MultiSurfaceStream::new(source_text)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].surface_member().len(), 1);
+        assert_eq!(results[1].surface_member().len(), 1);
+    }
+
+    #[test]
+    fn streaming_surfaces_a_byte_offset_on_parse_failure() {
+        let source_text = "<city:CityModel>
+              <gml:MultiSurface gml:id=\"broken\">
+                <gml:surfaceMember xlink:href=\"#missing\"/>
+              </gml:MultiSurface>
+            </city:CityModel>";
+
+        let result = MultiSurfaceStream::new(source_text)
+            .unwrap()
+            .next()
+            .unwrap();
+
+        assert!(matches!(result, Err(Error::StreamElement { .. })));
+    }
+
+    #[test]
+    fn streaming_yields_the_resolved_crs_alongside_the_surface() {
+        let source_text = "<gml:MultiSurface srsName=\"EPSG:25832\" srsDimension=\"3\">
+              <gml:surfaceMember>
+                <gml:Polygon>
+                  <gml:exterior>
+                    <gml:LinearRing>
+                      <gml:posList>0 0 0 1 0 0 1 1 0 0 1 0 0 0 0</gml:posList>
+                    </gml:LinearRing>
+                  </gml:exterior>
+                </gml:Polygon>
+              </gml:surfaceMember>
+            </gml:MultiSurface>";
+
+        let (multi_surface, crs) = MultiSurfaceStream::new(source_text)
+            .unwrap()
+            .next_with_crs()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(multi_surface.surface_member().len(), 1);
+        assert_eq!(crs.srs_name.as_deref(), Some("EPSG:25832"));
+        assert_eq!(crs.epsg, Some(25832));
+    }
+}
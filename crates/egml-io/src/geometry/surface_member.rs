@@ -0,0 +1,75 @@
+use crate::error::Error;
+
+use egml_core::model::geometry::Polygon;
+use serde::{Deserialize, Serialize};
+
+use crate::geometry::context::ParseContext;
+use crate::geometry::polygon::{to_gml_polygon, GmlPolygon, GmlPolygonWrite};
+
+/// A `gml:surfaceMember`, either an inline `gml:Polygon` or an `xlink:href`
+/// pointing at one declared elsewhere in the document. Shared by
+/// `gml:MultiSurface` and `gml:CompositeSurface`, which both just wrap a bag
+/// of these.
+#[derive(Debug, Deserialize, PartialEq, Eq, Hash, Clone)]
+#[serde(rename = "gml:surfaceMember")]
+pub struct GmlSurfaceMember {
+    #[serde(rename = "@href", default)]
+    href: String,
+    #[serde(rename = "$value")]
+    pub polygon: Option<GmlPolygon>,
+}
+
+/// Resolves every member to a [`Polygon`], dereferencing `xlink:href`s
+/// through `ctx`'s registry. A member with neither a polygon nor an href is
+/// dropped, matching the historical `flat_map` behaviour; an unresolvable
+/// href is an error rather than a silently incomplete surface.
+pub(crate) fn build_polygons(
+    members: Vec<GmlSurfaceMember>,
+    ctx: &ParseContext,
+) -> Result<Vec<Polygon>, Error> {
+    members
+        .into_iter()
+        .filter_map(|member| resolve_member(member, ctx).transpose())
+        .map(|x| x.and_then(|polygon| polygon.try_into_with(ctx)))
+        .collect()
+}
+
+fn resolve_member(
+    member: GmlSurfaceMember,
+    ctx: &ParseContext,
+) -> Result<Option<GmlPolygon>, Error> {
+    if let Some(polygon) = member.polygon {
+        return Ok(Some(polygon));
+    }
+    if member.href.is_empty() {
+        return Ok(None);
+    }
+    let registry = ctx
+        .registry
+        .ok_or_else(|| Error::DanglingReference(member.href.clone()))?;
+    registry.resolve_polygon(&member.href).map(Some)
+}
+
+/// Write-side mirror of [`GmlSurfaceMember`]: the `xlink:href` attribute is
+/// skipped entirely when absent instead of being written as `xlink:href=""`,
+/// and keeps its namespace prefix (the read struct's `@href` matches either
+/// `href` or `xlink:href` by local name, but only the latter is conformant
+/// GML to write).
+#[derive(Debug, Serialize)]
+#[serde(rename = "gml:surfaceMember")]
+pub(crate) struct GmlSurfaceMemberWrite {
+    #[serde(rename = "@xlink:href", skip_serializing_if = "String::is_empty")]
+    href: String,
+    #[serde(rename = "$value")]
+    polygon: Option<GmlPolygonWrite>,
+}
+
+/// Inverse of [`resolve_member`]: always emits the polygon inline, since the
+/// domain model no longer distinguishes a member that was originally an
+/// `xlink:href` from one that was inline.
+pub(crate) fn to_gml_surface_member(polygon: &Polygon, precision: usize) -> GmlSurfaceMemberWrite {
+    GmlSurfaceMemberWrite {
+        href: String::new(),
+        polygon: Some(to_gml_polygon(polygon, precision)),
+    }
+}
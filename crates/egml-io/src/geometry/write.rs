@@ -0,0 +1,169 @@
+use egml_core::model::geometry::MultiSurface;
+
+use crate::error::Error;
+use crate::geometry::multi_surface::to_gml_multi_surface;
+
+/// Default number of decimal places used when formatting coordinates in a
+/// written `gml:posList`.
+pub const DEFAULT_COORDINATE_PRECISION: usize = 9;
+
+/// Controls how a [`MultiSurface`] is rendered back to GML text.
+#[derive(Debug, Clone)]
+pub struct WriteOptions {
+    /// `srsName` to tag the written `gml:MultiSurface` with. The domain
+    /// model itself is CRS-agnostic, so this is taken from the caller rather
+    /// than recovered from the parsed document.
+    pub srs_name: Option<String>,
+    /// Number of decimal places each coordinate is formatted with.
+    pub precision: usize,
+}
+
+impl Default for WriteOptions {
+    fn default() -> Self {
+        Self {
+            srs_name: None,
+            precision: DEFAULT_COORDINATE_PRECISION,
+        }
+    }
+}
+
+/// Serializes `value` back to a `gml:MultiSurface` document using the
+/// default [`WriteOptions`].
+pub fn write_multi_surface(value: &MultiSurface) -> Result<String, Error> {
+    write_multi_surface_with(value, &WriteOptions::default())
+}
+
+/// Like [`write_multi_surface`], but with caller-controlled `srsName` and
+/// coordinate precision.
+pub fn write_multi_surface_with(
+    value: &MultiSurface,
+    options: &WriteOptions,
+) -> Result<String, Error> {
+    let gml = to_gml_multi_surface(value, options.srs_name.clone(), options.precision);
+    let xml = quick_xml::se::to_string(&gml)?;
+    Ok(xml)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::write::{write_multi_surface, write_multi_surface_with, WriteOptions};
+    use crate::parse_multi_surface;
+
+    #[test]
+    fn round_trips_a_simple_multi_surface() {
+        let source_text = "<gml:MultiSurface gml:id=\"UUID_roof\">
+              <gml:surfaceMember>
+                <gml:Polygon>
+                  <gml:exterior>
+                    <gml:LinearRing>
+                      <gml:posList srsDimension=\"3\">0 0 0 1 0 0 1 1 0 0 1 0 0 0 0</gml:posList>
+                    </gml:LinearRing>
+                  </gml:exterior>
+                </gml:Polygon>
+              </gml:surfaceMember>
+            </gml:MultiSurface>";
+
+        let original = parse_multi_surface(source_text).unwrap();
+
+        let written = write_multi_surface(&original).unwrap();
+        let reparsed = parse_multi_surface(&written).unwrap();
+
+        assert_eq!(reparsed.id(), original.id());
+        assert_eq!(
+            reparsed.surface_member().len(),
+            original.surface_member().len()
+        );
+
+        let original_points = original.surface_member()[0].exterior().points();
+        let reparsed_points = reparsed.surface_member()[0].exterior().points();
+        assert_eq!(reparsed_points.len(), original_points.len());
+        for (reparsed_point, original_point) in reparsed_points.iter().zip(original_points) {
+            assert!((reparsed_point.x() - original_point.x()).abs() < 1e-9);
+            assert!((reparsed_point.y() - original_point.y()).abs() < 1e-9);
+            assert!((reparsed_point.z() - original_point.z()).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn round_trips_interior_rings() {
+        let source_text = "<gml:MultiSurface gml:id=\"UUID_wall_with_window\">
+              <gml:surfaceMember>
+                <gml:Polygon>
+                  <gml:exterior>
+                    <gml:LinearRing>
+                      <gml:posList srsDimension=\"3\">0 0 0 4 0 0 4 0 4 0 0 4 0 0 0</gml:posList>
+                    </gml:LinearRing>
+                  </gml:exterior>
+                  <gml:interior>
+                    <gml:LinearRing>
+                      <gml:posList srsDimension=\"3\">1 0 1 2 0 1 2 0 2 1 0 2 1 0 1</gml:posList>
+                    </gml:LinearRing>
+                  </gml:interior>
+                </gml:Polygon>
+              </gml:surfaceMember>
+            </gml:MultiSurface>";
+
+        let original = parse_multi_surface(source_text).unwrap();
+
+        let written = write_multi_surface(&original).unwrap();
+        let reparsed = parse_multi_surface(&written).unwrap();
+
+        let original_polygon = &original.surface_member()[0];
+        let reparsed_polygon = &reparsed.surface_member()[0];
+        assert_eq!(
+            reparsed_polygon.interior().len(),
+            original_polygon.interior().len()
+        );
+        assert_eq!(
+            reparsed_polygon.interior()[0].points().len(),
+            original_polygon.interior()[0].points().len()
+        );
+    }
+
+    #[test]
+    fn writes_the_requested_srs_name() {
+        let source_text = "<gml:MultiSurface gml:id=\"UUID_tagged\">
+              <gml:surfaceMember>
+                <gml:Polygon>
+                  <gml:exterior>
+                    <gml:LinearRing>
+                      <gml:posList srsDimension=\"3\">0 0 0 1 0 0 1 1 0 0 0 0</gml:posList>
+                    </gml:LinearRing>
+                  </gml:exterior>
+                </gml:Polygon>
+              </gml:surfaceMember>
+            </gml:MultiSurface>";
+
+        let original = parse_multi_surface(source_text).unwrap();
+        let options = WriteOptions {
+            srs_name: Some("EPSG:25832".to_string()),
+            ..WriteOptions::default()
+        };
+
+        let written = write_multi_surface_with(&original, &options).unwrap();
+
+        assert!(written.contains("EPSG:25832"));
+    }
+
+    #[test]
+    fn writes_the_gml_and_xlink_namespace_declarations() {
+        let source_text = "<gml:MultiSurface gml:id=\"UUID_namespaced\">
+              <gml:surfaceMember>
+                <gml:Polygon>
+                  <gml:exterior>
+                    <gml:LinearRing>
+                      <gml:posList srsDimension=\"3\">0 0 0 1 0 0 1 1 0 0 0 0</gml:posList>
+                    </gml:LinearRing>
+                  </gml:exterior>
+                </gml:Polygon>
+              </gml:surfaceMember>
+            </gml:MultiSurface>";
+
+        let original = parse_multi_surface(source_text).unwrap();
+
+        let written = write_multi_surface(&original).unwrap();
+
+        assert!(written.contains("xmlns:gml=\"http://www.opengis.net/gml\""));
+        assert!(written.contains("xmlns:xlink=\"http://www.w3.org/1999/xlink\""));
+    }
+}
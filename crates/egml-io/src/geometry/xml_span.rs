@@ -0,0 +1,59 @@
+use std::ops::Range;
+
+use quick_xml::events::Event;
+use quick_xml::reader::Reader;
+
+use crate::error::Error;
+
+/// Consumes events up to and including the matching closing tag for the
+/// element that starts at `start_position`, and returns the byte range it
+/// spans in `reader`'s underlying source. Used to carve a single element (a
+/// `gml:Polygon`, a `gml:MultiSurface`, ...) out of a larger document without
+/// materializing the whole document as one struct tree.
+pub(crate) fn scan_element_range(
+    reader: &mut Reader<&[u8]>,
+    tag_name: &[u8],
+    start_position: u64,
+) -> Result<Range<usize>, Error> {
+    let mut depth = 1u32;
+    let mut buf = Vec::new();
+    loop {
+        match reader
+            .read_event_into(&mut buf)
+            .map_err(|source| Error::StreamElement {
+                offset: start_position,
+                source: Box::new(source.into()),
+            })? {
+            Event::Start(start) if start.name().as_ref() == tag_name => depth += 1,
+            Event::End(end) if end.name().as_ref() == tag_name => {
+                depth -= 1;
+                if depth == 0 {
+                    let end_position = reader.buffer_position();
+                    return Ok(start_position as usize..end_position as usize);
+                }
+            }
+            Event::Eof => {
+                return Err(Error::StreamElement {
+                    offset: start_position,
+                    source: Box::new(Error::UnexpectedEof(
+                        String::from_utf8_lossy(tag_name).into_owned(),
+                    )),
+                })
+            }
+            _ => {}
+        }
+        buf.clear();
+    }
+}
+
+/// Like [`scan_element_range`], but returns the exact source slice spanning
+/// the element rather than just its byte range.
+pub(crate) fn read_element_span(
+    reader: &mut Reader<&[u8]>,
+    tag_name: &[u8],
+    start_position: u64,
+) -> Result<String, Error> {
+    let source = std::str::from_utf8(reader.get_ref()).map_err(|_| Error::InvalidUtf8)?;
+    let range = scan_element_range(reader, tag_name, start_position)?;
+    Ok(source[range].to_string())
+}
@@ -0,0 +1,15 @@
+pub mod crs;
+pub mod error;
+pub mod geometry;
+pub mod mesh;
+
+pub use crs::ReprojectionTarget;
+pub use error::Error;
+pub use geometry::composite_surface::parse_composite_surface;
+pub use geometry::multi_surface::{
+    parse_multi_surface, parse_multi_surface_reprojected, parse_multi_surface_with_crs,
+};
+pub use geometry::solid::parse_solid;
+pub use geometry::stream::MultiSurfaceStream;
+pub use geometry::write::{write_multi_surface, write_multi_surface_with, WriteOptions};
+pub use mesh::{TriangleMesh, Triangulate};
@@ -0,0 +1,437 @@
+use crate::error::Error;
+use egml_core::model::geometry::{MultiSurface, Point3D, Polygon, Ring};
+
+/// A flat, renderer-friendly mesh: every three consecutive `indices` form one
+/// triangle into `positions`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TriangleMesh {
+    pub positions: Vec<[f64; 3]>,
+    pub indices: Vec<u32>,
+}
+
+impl TriangleMesh {
+    /// Renders the mesh as a minimal Wavefront OBJ (`v`/`f` records only).
+    pub fn to_obj(&self) -> String {
+        let mut out = String::new();
+        for p in &self.positions {
+            out.push_str(&format!("v {} {} {}\n", p[0], p[1], p[2]));
+        }
+        for face in self.indices.chunks(3) {
+            // OBJ face indices are 1-based.
+            out.push_str(&format!(
+                "f {} {} {}\n",
+                face[0] + 1,
+                face[1] + 1,
+                face[2] + 1
+            ));
+        }
+        out
+    }
+}
+
+/// Triangulation export for geometry that renderers consume as triangles
+/// rather than polygons-with-holes.
+pub trait Triangulate {
+    fn triangulate(&self) -> Result<TriangleMesh, Error>;
+
+    fn to_obj(&self) -> Result<String, Error> {
+        Ok(self.triangulate()?.to_obj())
+    }
+}
+
+impl Triangulate for MultiSurface {
+    fn triangulate(&self) -> Result<TriangleMesh, Error> {
+        let mut mesh = TriangleMesh::default();
+        for polygon in self.surface_member() {
+            triangulate_polygon_into(polygon, &mut mesh)?;
+        }
+        Ok(mesh)
+    }
+}
+
+fn triangulate_polygon_into(polygon: &Polygon, mesh: &mut TriangleMesh) -> Result<(), Error> {
+    let exterior = ring_points(polygon.exterior());
+    let interiors: Vec<Vec<Point3D>> = polygon.interior().iter().map(ring_points).collect();
+
+    let all_points = exterior.iter().chain(interiors.iter().flatten());
+    let drop_axis = dominant_axis(newell_normal(all_points));
+
+    let exterior_2d = project(&exterior, drop_axis);
+    let mut boundary_2d = exterior_2d;
+    let mut boundary_3d = exterior;
+
+    for interior in interiors {
+        let interior_2d = project(&interior, drop_axis);
+        bridge_hole(&mut boundary_2d, &mut boundary_3d, interior_2d, interior)?;
+    }
+
+    let base = mesh.positions.len() as u32;
+    mesh.positions
+        .extend(boundary_3d.iter().map(|p| [p.x(), p.y(), p.z()]));
+
+    for [a, b, c] in ear_clip(&boundary_2d) {
+        mesh.indices.push(base + a as u32);
+        mesh.indices.push(base + b as u32);
+        mesh.indices.push(base + c as u32);
+    }
+
+    Ok(())
+}
+
+/// Returns a ring's vertices without the closing duplicate of the first
+/// point, which every posList in the test fixtures repeats explicitly.
+fn ring_points(ring: &Ring) -> Vec<Point3D> {
+    let mut points = ring.points().clone();
+    if points.len() > 1 && points_coincide(points.first().unwrap(), points.last().unwrap()) {
+        points.pop();
+    }
+    points
+}
+
+fn points_coincide(a: &Point3D, b: &Point3D) -> bool {
+    const EPSILON: f64 = 1e-9;
+    (a.x() - b.x()).abs() < EPSILON
+        && (a.y() - b.y()).abs() < EPSILON
+        && (a.z() - b.z()).abs() < EPSILON
+}
+
+/// Sums the Newell normal over a polygon's boundary loop(s), which tolerates
+/// non-planar and degenerate input far better than a three-point cross
+/// product.
+fn newell_normal<'a>(points: impl Iterator<Item = &'a Point3D>) -> [f64; 3] {
+    let points: Vec<&Point3D> = points.collect();
+    let mut normal = [0.0, 0.0, 0.0];
+    for i in 0..points.len() {
+        let a = points[i];
+        let b = points[(i + 1) % points.len()];
+        normal[0] += (a.y() - b.y()) * (a.z() + b.z());
+        normal[1] += (a.z() - b.z()) * (a.x() + b.x());
+        normal[2] += (a.x() - b.x()) * (a.y() + b.y());
+    }
+    normal
+}
+
+/// Picks the axis to drop when flattening to 2D: the one the normal points
+/// most along, so the projection keeps the most surface area.
+fn dominant_axis(normal: [f64; 3]) -> usize {
+    let abs = normal.map(f64::abs);
+    if abs[0] >= abs[1] && abs[0] >= abs[2] {
+        0
+    } else if abs[1] >= abs[2] {
+        1
+    } else {
+        2
+    }
+}
+
+fn project(points: &[Point3D], drop_axis: usize) -> Vec<[f64; 2]> {
+    points
+        .iter()
+        .map(|p| match drop_axis {
+            0 => [p.y(), p.z()],
+            1 => [p.x(), p.z()],
+            _ => [p.x(), p.y()],
+        })
+        .collect()
+}
+
+fn signed_area(points: &[[f64; 2]]) -> f64 {
+    let mut area = 0.0;
+    for i in 0..points.len() {
+        let [x1, y1] = points[i];
+        let [x2, y2] = points[(i + 1) % points.len()];
+        area += x1 * y2 - x2 * y1;
+    }
+    area / 2.0
+}
+
+/// Splices `hole` into `boundary` via a bridge edge from the hole's
+/// rightmost vertex to a mutually-visible boundary vertex, turning the
+/// polygon-with-hole into a single simple polygon that ear clipping can
+/// consume directly.
+fn bridge_hole(
+    boundary_2d: &mut Vec<[f64; 2]>,
+    boundary_3d: &mut Vec<Point3D>,
+    mut hole_2d: Vec<[f64; 2]>,
+    mut hole_3d: Vec<Point3D>,
+) -> Result<(), Error> {
+    if hole_2d.is_empty() {
+        return Ok(());
+    }
+
+    // A bridge only produces a simple polygon if the hole winds opposite to
+    // the outer boundary.
+    if signed_area(boundary_2d).signum() == signed_area(&hole_2d).signum() {
+        hole_2d.reverse();
+        hole_3d.reverse();
+    }
+
+    let rightmost = (0..hole_2d.len())
+        .max_by(|&a, &b| hole_2d[a][0].partial_cmp(&hole_2d[b][0]).unwrap())
+        .unwrap();
+
+    let visible = find_visible_vertex(boundary_2d, hole_2d[rightmost])?;
+
+    let mut new_boundary_2d = Vec::with_capacity(boundary_2d.len() + hole_2d.len() + 2);
+    let mut new_boundary_3d = Vec::with_capacity(boundary_3d.len() + hole_3d.len() + 2);
+    for i in 0..=visible {
+        new_boundary_2d.push(boundary_2d[i]);
+        new_boundary_3d.push(boundary_3d[i].clone());
+    }
+    for offset in 0..=hole_2d.len() {
+        let i = (rightmost + offset) % hole_2d.len();
+        new_boundary_2d.push(hole_2d[i]);
+        new_boundary_3d.push(hole_3d[i].clone());
+    }
+    for i in visible..boundary_2d.len() {
+        new_boundary_2d.push(boundary_2d[i]);
+        new_boundary_3d.push(boundary_3d[i].clone());
+    }
+
+    *boundary_2d = new_boundary_2d;
+    *boundary_3d = new_boundary_3d;
+    Ok(())
+}
+
+/// Finds a boundary vertex visible from `from` (the segment between them
+/// crosses no boundary edge), preferring the closest such vertex.
+fn find_visible_vertex(boundary: &[[f64; 2]], from: [f64; 2]) -> Result<usize, Error> {
+    let n = boundary.len();
+    let mut best: Option<(usize, f64)> = None;
+    for i in 0..n {
+        let candidate = boundary[i];
+        let blocked = (0..n).any(|edge| {
+            let a = boundary[edge];
+            let b = boundary[(edge + 1) % n];
+            (a != from && a != candidate && b != from && b != candidate)
+                && segments_intersect(from, candidate, a, b)
+        });
+        if blocked {
+            continue;
+        }
+
+        let distance = (candidate[0] - from[0]).powi(2) + (candidate[1] - from[1]).powi(2);
+        if best
+            .map(|(_, best_distance)| distance < best_distance)
+            .unwrap_or(true)
+        {
+            best = Some((i, distance));
+        }
+    }
+    best.map(|(i, _)| i).ok_or(Error::NoVisibleBridgeVertex)
+}
+
+fn cross2(o: [f64; 2], a: [f64; 2], b: [f64; 2]) -> f64 {
+    (a[0] - o[0]) * (b[1] - o[1]) - (a[1] - o[1]) * (b[0] - o[0])
+}
+
+fn segments_intersect(p1: [f64; 2], p2: [f64; 2], p3: [f64; 2], p4: [f64; 2]) -> bool {
+    let d1 = cross2(p3, p4, p1);
+    let d2 = cross2(p3, p4, p2);
+    let d3 = cross2(p1, p2, p3);
+    let d4 = cross2(p1, p2, p4);
+    ((d1 > 0.0 && d2 < 0.0) || (d1 < 0.0 && d2 > 0.0))
+        && ((d3 > 0.0 && d4 < 0.0) || (d3 < 0.0 && d4 > 0.0))
+}
+
+fn triangle_area2(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> f64 {
+    cross2(a, b, c)
+}
+
+fn point_in_triangle(p: [f64; 2], a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> bool {
+    let d1 = cross2(a, b, p);
+    let d2 = cross2(b, c, p);
+    let d3 = cross2(c, a, p);
+    let has_neg = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+    let has_pos = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+    !(has_neg && has_pos)
+}
+
+/// Ear-clips a simple (possibly non-convex) 2D polygon, returning triangles
+/// as index triples into `points`. Zero-area ears, which the test fixtures'
+/// duplicate/degenerate vertices can produce, are dropped rather than
+/// emitted.
+fn ear_clip(points: &[[f64; 2]]) -> Vec<[usize; 3]> {
+    let mut indices: Vec<usize> = (0..points.len()).collect();
+    let mut triangles = Vec::new();
+    let is_ccw = signed_area(points) >= 0.0;
+
+    while indices.len() > 3 {
+        let n = indices.len();
+        let mut clipped = false;
+
+        for i in 0..n {
+            let prev = indices[(i + n - 1) % n];
+            let curr = indices[i];
+            let next = indices[(i + 1) % n];
+
+            let turn = cross2(points[prev], points[curr], points[next]);
+            let is_convex = if is_ccw { turn >= 0.0 } else { turn <= 0.0 };
+            if !is_convex {
+                continue;
+            }
+
+            let contains_other_vertex = indices.iter().any(|&j| {
+                j != prev
+                    && j != curr
+                    && j != next
+                    && point_in_triangle(points[j], points[prev], points[curr], points[next])
+            });
+            if contains_other_vertex {
+                continue;
+            }
+
+            if triangle_area2(points[prev], points[curr], points[next]).abs() > 1e-12 {
+                triangles.push([prev, curr, next]);
+            }
+            indices.remove(i);
+            clipped = true;
+            break;
+        }
+
+        if !clipped {
+            // No valid ear left (degenerate input) - stop rather than loop forever.
+            break;
+        }
+    }
+
+    if indices.len() == 3
+        && triangle_area2(points[indices[0]], points[indices[1]], points[indices[2]]).abs() > 1e-12
+    {
+        triangles.push([indices[0], indices[1], indices[2]]);
+    }
+
+    triangles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_multi_surface;
+
+    #[test]
+    fn triangulating_a_simple_square() {
+        let source_text = "<gml:MultiSurface>
+              <gml:surfaceMember>
+                <gml:Polygon>
+                  <gml:exterior>
+                    <gml:LinearRing>
+                      <gml:posList srsDimension=\"3\">0 0 0 1 0 0 1 1 0 0 1 0 0 0 0</gml:posList>
+                    </gml:LinearRing>
+                  </gml:exterior>
+                </gml:Polygon>
+              </gml:surfaceMember>
+            </gml:MultiSurface>";
+
+        let multi_surface = parse_multi_surface(source_text).unwrap();
+        let mesh = multi_surface.triangulate().unwrap();
+
+        assert_eq!(mesh.positions.len(), 4);
+        assert_eq!(mesh.indices.len(), 6);
+    }
+
+    #[test]
+    fn triangulating_with_holes_produces_no_degenerate_triangles() {
+        let source_text = "
+            <gml:MultiSurface srsName=\"EPSG:25832\" srsDimension=\"3\">
+              <gml:surfaceMember>
+                <gml:Polygon gml:id=\"4018106_PG.dKY9ug9ol2tsxL5bLAPz\">
+                  <gml:exterior>
+                    <gml:LinearRing gml:id=\"4018106_LR.Wqmtl1E6Yz3eVJkuGjsK\">
+                      <gml:posList>678097.805 5403801.433 367.40123 678092.938 5403810.139 367.40123 678092.938 5403810.139 370.87623 678092.032 5403811.76 370.87623 678092.032 5403811.76 377.09023 678097.805 5403801.433 377.09023 678097.805 5403801.433 367.40123</gml:posList>
+                    </gml:LinearRing>
+                  </gml:exterior>
+                  <gml:interior>
+                    <gml:LinearRing gml:id=\"4018106_LR.10JNDsQqif3fouy54mfv\">
+                      <gml:posList>678096.88 5403803.088 374.90623 678097.403 5403802.152 374.90623 678097.403 5403802.152 376.19923 678096.88 5403803.088 376.19923 678096.88 5403803.088 374.90623</gml:posList>
+                    </gml:LinearRing>
+                  </gml:interior>
+                </gml:Polygon>
+              </gml:surfaceMember>
+            </gml:MultiSurface>";
+
+        let multi_surface = parse_multi_surface(source_text).unwrap();
+        let mesh = multi_surface.triangulate().unwrap();
+
+        assert!(!mesh.indices.is_empty());
+        assert_eq!(mesh.indices.len() % 3, 0);
+        for triangle in mesh.indices.chunks(3) {
+            let a = mesh.positions[triangle[0] as usize];
+            let b = mesh.positions[triangle[1] as usize];
+            let c = mesh.positions[triangle[2] as usize];
+            let u = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+            let v = [c[0] - a[0], c[1] - a[1], c[2] - a[2]];
+            let cross = [
+                u[1] * v[2] - u[2] * v[1],
+                u[2] * v[0] - u[0] * v[2],
+                u[0] * v[1] - u[1] * v[0],
+            ];
+            assert!(cross.iter().map(|c| c * c).sum::<f64>() > 0.0);
+        }
+    }
+
+    #[test]
+    fn triangulating_with_multiple_holes_preserves_area() {
+        let source_text = "<gml:MultiSurface>
+              <gml:surfaceMember>
+                <gml:Polygon>
+                  <gml:exterior>
+                    <gml:LinearRing>
+                      <gml:posList srsDimension=\"3\">0 0 0 10 0 0 10 10 0 0 10 0 0 0 0</gml:posList>
+                    </gml:LinearRing>
+                  </gml:exterior>
+                  <gml:interior>
+                    <gml:LinearRing>
+                      <gml:posList srsDimension=\"3\">1 1 0 2 1 0 2 2 0 1 2 0 1 1 0</gml:posList>
+                    </gml:LinearRing>
+                  </gml:interior>
+                  <gml:interior>
+                    <gml:LinearRing>
+                      <gml:posList srsDimension=\"3\">5 5 0 6 5 0 6 6 0 5 6 0 5 5 0</gml:posList>
+                    </gml:LinearRing>
+                  </gml:interior>
+                </gml:Polygon>
+              </gml:surfaceMember>
+            </gml:MultiSurface>";
+
+        let multi_surface = parse_multi_surface(source_text).unwrap();
+        let mesh = multi_surface.triangulate().unwrap();
+
+        // Two 1x1 holes bridged out of a 10x10 exterior: whatever the exact
+        // triangle count, the total area they cover must equal the exterior
+        // minus both holes.
+        let area: f64 = mesh
+            .indices
+            .chunks(3)
+            .map(|triangle| {
+                let a = mesh.positions[triangle[0] as usize];
+                let b = mesh.positions[triangle[1] as usize];
+                let c = mesh.positions[triangle[2] as usize];
+                ((b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])).abs() / 2.0
+            })
+            .sum();
+
+        assert!((area - 98.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn writing_obj() {
+        let source_text = "<gml:MultiSurface>
+              <gml:surfaceMember>
+                <gml:Polygon>
+                  <gml:exterior>
+                    <gml:LinearRing>
+                      <gml:posList srsDimension=\"3\">0 0 0 1 0 0 1 1 0 0 1 0 0 0 0</gml:posList>
+                    </gml:LinearRing>
+                  </gml:exterior>
+                </gml:Polygon>
+              </gml:surfaceMember>
+            </gml:MultiSurface>";
+
+        let multi_surface = parse_multi_surface(source_text).unwrap();
+        let obj = multi_surface.to_obj().unwrap();
+
+        assert!(obj.starts_with("v "));
+        assert!(obj.contains("f "));
+    }
+}